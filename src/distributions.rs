@@ -0,0 +1,71 @@
+//! Non-uniform distributions layered on top of the generators. The normal and
+//! exponential variates are produced with the ziggurat method, the same
+//! layered-rejection technique used by the wider `rand` ecosystem, sharing the
+//! precomputed tables with the `NonCanonical` trait.
+
+use crate::float::{ziggurat_exp, ziggurat_normal};
+use crate::generators::{Xoroshiro256pp, Xorshift128p};
+
+macro_rules! impl_distributions {
+    ($gen:ty) => {
+        impl $gen {
+            /// Draws an `f64` from the standard normal distribution (mean `0.0`,
+            /// standard deviation `1.0`) using the ziggurat method.
+            /// # Examples
+            /// ```rust
+            /// use floaters::generators::Xoroshiro256pp;
+            /// let mut rng = Xoroshiro256pp::seed_from_u64(12345);
+            /// let n = 500_000;
+            /// let (mut sum, mut sq) = (0.0f64, 0.0f64);
+            /// for _ in 0..n {
+            ///     let x = rng.normal_f64();
+            ///     sum += x;
+            ///     sq += x * x;
+            /// }
+            /// let mean = sum / n as f64;
+            /// let var = sq / n as f64 - mean * mean;
+            /// assert!(mean.abs() < 0.02 && (var - 1.0).abs() < 0.02);
+            /// ```
+            pub fn normal_f64(&mut self) -> f64 {
+                ziggurat_normal(|| self.next_u64())
+            }
+
+            /// Draws an `f64` from a normal distribution with the given `mean`
+            /// and standard deviation `std_dev` by scaling a standard normal
+            /// variate.
+            pub fn normal_scaled_f64(&mut self, mean: f64, std_dev: f64) -> f64 {
+                mean + std_dev * self.normal_f64()
+            }
+
+            /// Draws an `f64` from the unit exponential distribution (rate
+            /// `1.0`) using the ziggurat method.
+            /// # Examples
+            /// ```rust
+            /// use floaters::generators::Xoroshiro256pp;
+            /// let mut rng = Xoroshiro256pp::seed_from_u64(6789);
+            /// let n = 500_000;
+            /// let (mut sum, mut sq) = (0.0f64, 0.0f64);
+            /// for _ in 0..n {
+            ///     let x = rng.exp_distributed_f64();
+            ///     sum += x;
+            ///     sq += x * x;
+            /// }
+            /// let mean = sum / n as f64;
+            /// let var = sq / n as f64 - mean * mean;
+            /// assert!((mean - 1.0).abs() < 0.02 && (var - 1.0).abs() < 0.03);
+            /// ```
+            pub fn exp_distributed_f64(&mut self) -> f64 {
+                ziggurat_exp(|| self.next_u64())
+            }
+
+            /// Draws an `f64` from an exponential distribution with the given
+            /// `rate` (`lambda`) by scaling a unit exponential variate.
+            pub fn exp_scaled_f64(&mut self, rate: f64) -> f64 {
+                self.exp_distributed_f64() / rate
+            }
+        }
+    };
+}
+
+impl_distributions!(Xorshift128p);
+impl_distributions!(Xoroshiro256pp);
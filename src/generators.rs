@@ -4,6 +4,41 @@
 use crate::getrandom_nonzero64vec;
 use crate::Sign;
 
+/// One round of SplitMix64, the recommended way to expand a single 64 bit
+/// seed into the larger, well-distributed state the xoshiro family expects.
+/// The running `state` is advanced in place so repeated calls yield the
+/// successive state words.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+/// The largest `f64` strictly less than `hi`, used to keep a ranged draw from
+/// rounding up onto the exclusive upper bound.
+fn next_below_f64(hi: f64) -> f64 {
+    if hi > 0.0 {
+        f64::from_bits(hi.to_bits() - 1)
+    } else if hi < 0.0 {
+        f64::from_bits(hi.to_bits() + 1)
+    } else {
+        -f64::from_bits(1)
+    }
+}
+
+/// The largest `f32` strictly less than `hi`.
+fn next_below_f32(hi: f32) -> f32 {
+    if hi > 0.0 {
+        f32::from_bits(hi.to_bits() - 1)
+    } else if hi < 0.0 {
+        f32::from_bits(hi.to_bits() + 1)
+    } else {
+        -f32::from_bits(1)
+    }
+}
+
 #[derive(Debug,Copy,Clone)]
 pub struct Xorshift128p {
     pub state: [u64; 2],
@@ -95,6 +130,23 @@ impl Xorshift128p {
         }
     }
 
+    /// Create a new state for the Xorshift128+ generator from a single 64 bit
+    /// seed, expanded through SplitMix64 to fill both state words. This is the
+    /// recommended initialization and guarantees a well-distributed, non-zero
+    /// state from any input.
+    /// # Examples
+    /// ```rust
+    /// use floaters::generators::Xorshift128p;
+    /// let mut x128p = Xorshift128p::seed_from_u64(0);
+    /// assert!(x128p.state[0] != 0 || x128p.state[1] != 0);
+    /// ```
+    pub fn seed_from_u64(x: u64) -> Self {
+        let mut z = x;
+        Self {
+            state: [splitmix64(&mut z), splitmix64(&mut z)],
+        }
+    }
+
     fn clock(&mut self) {
         let mut t = self.state[0];
         let s = self.state[1];
@@ -221,6 +273,93 @@ impl Xorshift128p {
         self.clock();
         crate::float::tuple_exp(self.sum(), exponent, signed) 
     }
+
+    /// Generates an `f64` uniformly distributed in the half-open range
+    /// `[lo, hi)`, built on `canonical_f64`. The result is clamped to the
+    /// largest float below `hi` so the multiply-add cannot round up onto the
+    /// excluded upper bound.
+    pub fn range_f64(&mut self, lo: f64, hi: f64) -> f64 {
+        debug_assert!(lo < hi && lo.is_finite() && hi.is_finite());
+        let x = lo + (hi - lo) * self.canonical_f64();
+        if x < hi { x } else { next_below_f64(hi) }
+    }
+
+    /// Generates a closed-range `f64` uniformly distributed in `[lo, hi]`,
+    /// using a full 53 bit fraction so both bounds are attainable.
+    pub fn range_inclusive_f64(&mut self, lo: f64, hi: f64) -> f64 {
+        debug_assert!(lo <= hi && lo.is_finite() && hi.is_finite());
+        self.clock();
+        let u = (self.sum() >> 11) as f64 / ((1u64 << 53) - 1) as f64;
+        lo + (hi - lo) * u
+    }
+
+    /// Generates an `f32` tuple uniformly distributed in `[lo, hi)`, built on
+    /// `tuple_canonical_f32`, with each element clamped below `hi`.
+    pub fn range_f32(&mut self, lo: f32, hi: f32) -> (f32, f32) {
+        debug_assert!(lo < hi && lo.is_finite() && hi.is_finite());
+        let (a, b) = self.tuple_canonical_f32();
+        let map = |u: f32| {
+            let x = lo + (hi - lo) * u;
+            if x < hi { x } else { next_below_f32(hi) }
+        };
+        (map(a), map(b))
+    }
+
+    /// Generates a `u64` uniformly distributed in `[lo, hi)` using Lemire's
+    /// unbiased method: the draw is multiplied by the range width as a 128 bit
+    /// product and rejected only within the small low-bias zone.
+    pub fn range_u64(&mut self, lo: u64, hi: u64) -> u64 {
+        debug_assert!(lo < hi);
+        let range = hi - lo;
+        self.clock();
+        let mut product = (self.sum() as u128) * (range as u128);
+        let mut low = product as u64;
+        if low < range {
+            let threshold = range.wrapping_neg() % range;
+            while low < threshold {
+                self.clock();
+                product = (self.sum() as u128) * (range as u128);
+                low = product as u64;
+            }
+        }
+        lo + (product >> 64) as u64
+    }
+
+    /// Clocks the generator once and returns the resulting `u64`. This is the
+    /// raw integer output underpinning every float method, exposed for use
+    /// cases such as hashing salts, index selection and byte streams.
+    pub fn next_u64(&mut self) -> u64 {
+        self.clock();
+        self.sum()
+    }
+
+    /// Returns the high 32 bits of the next `u64`, which on the xoshiro family
+    /// are of higher quality than the low bits.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Fills `dst` with freshly generated `u64`s, clocking once per word in a
+    /// tight loop to amortize the per-call overhead.
+    pub fn fill_u64(&mut self, dst: &mut [u64]) {
+        for slot in dst.iter_mut() {
+            *slot = self.next_u64();
+        }
+    }
+
+    /// Fills `dst` with pseudorandom bytes, drawing one `u64` per 8 bytes and
+    /// copying a partial tail for any remainder.
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let bytes = self.next_u64().to_le_bytes();
+            tail.copy_from_slice(&bytes[..tail.len()]);
+        }
+    }
     
 }
 
@@ -315,8 +454,30 @@ impl Xoroshiro256pp {
         }
     }
 
+    /// Create a new state for the Xoroshiro256++ generator from a single 64 bit
+    /// seed, expanded through SplitMix64 to fill all four state words. This is
+    /// the recommended initialization and guarantees a well-distributed,
+    /// non-zero state from any input.
+    /// # Examples
+    /// ```rust
+    /// use floaters::generators::Xoroshiro256pp;
+    /// let mut xrsr256pp = Xoroshiro256pp::seed_from_u64(0);
+    /// assert!(xrsr256pp.state.iter().any(|&w| w != 0));
+    /// ```
+    pub fn seed_from_u64(x: u64) -> Self {
+        let mut z = x;
+        Self {
+            state: [
+                splitmix64(&mut z),
+                splitmix64(&mut z),
+                splitmix64(&mut z),
+                splitmix64(&mut z),
+            ],
+        }
+    }
+
     fn clock(&mut self) {
-        
+
         let t: u64 = self.state[1] << 17;
 
         self.state[2] ^= self.state[0];
@@ -344,6 +505,64 @@ impl Xoroshiro256pp {
         }
     }
 
+    /// Advance the state by 2^128 calls in one step, equivalent to clocking the
+    /// generator that many times. Useful for creating 2^128 non-overlapping
+    /// subsequences that can be assigned to parallel workers.
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] = [
+            0x180ec6d33cfd0aba, 0xd5a61266f0c9392c,
+            0xa9582618e03fc9aa, 0x39abdc4529b1661c,
+        ];
+        self.jump_by(&JUMP);
+    }
+
+    /// Advance the state by 2^192 calls in one step. Successive long jumps
+    /// carve the sequence into 2^64 subsequences, each itself long enough to
+    /// be split further with [`jump`](Xoroshiro256pp::jump).
+    pub fn long_jump(&mut self) {
+        const LONG_JUMP: [u64; 4] = [
+            0x76e15d3efefdcbbf, 0xc5004e441c522fb3,
+            0x77710069854ee241, 0x39109bb02acbe635,
+        ];
+        self.jump_by(&LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, constants: &[u64; 4]) {
+        let mut acc = [0u64; 4];
+        for &c in constants.iter() {
+            for b in 0..64 {
+                if c & (1u64 << b) != 0 {
+                    acc[0] ^= self.state[0];
+                    acc[1] ^= self.state[1];
+                    acc[2] ^= self.state[2];
+                    acc[3] ^= self.state[3];
+                }
+                self.clock();
+            }
+        }
+        self.state = acc;
+    }
+
+    /// Derive `n` decorrelated generators from the current one by cloning and
+    /// advancing with [`long_jump`](Xoroshiro256pp::long_jump) between each, so
+    /// every returned generator starts on its own non-overlapping subsequence.
+    /// The callee itself is left advanced past the last returned clone.
+    /// # Examples
+    /// ```rust
+    /// use floaters::generators::Xoroshiro256pp;
+    /// let mut base = Xoroshiro256pp::new_from_str("split me into workers");
+    /// let workers = base.split(8);
+    /// assert_eq!(workers.len(), 8);
+    /// ```
+    pub fn split(&mut self, n: usize) -> Vec<Xoroshiro256pp> {
+        let mut out = Vec::with_capacity(n);
+        for _ in 0..n {
+            out.push(*self);
+            self.long_jump();
+        }
+        out
+    }
+
     /// Generates an `f64`. Numbers generated by this method are roughly equidistributed
     /// in the unit interval.
     pub fn canonical_f64(&mut self) -> f64 {
@@ -448,5 +667,252 @@ impl Xoroshiro256pp {
         self.clock();
         crate::float::tuple_exp(self.sum(), exponent, signed) 
     }
+
+    /// Generates an `f64` uniformly distributed in the half-open range
+    /// `[lo, hi)`, built on `canonical_f64`. The result is clamped to the
+    /// largest float below `hi` so the multiply-add cannot round up onto the
+    /// excluded upper bound.
+    pub fn range_f64(&mut self, lo: f64, hi: f64) -> f64 {
+        debug_assert!(lo < hi && lo.is_finite() && hi.is_finite());
+        let x = lo + (hi - lo) * self.canonical_f64();
+        if x < hi { x } else { next_below_f64(hi) }
+    }
+
+    /// Generates a closed-range `f64` uniformly distributed in `[lo, hi]`,
+    /// using a full 53 bit fraction so both bounds are attainable.
+    pub fn range_inclusive_f64(&mut self, lo: f64, hi: f64) -> f64 {
+        debug_assert!(lo <= hi && lo.is_finite() && hi.is_finite());
+        self.clock();
+        let u = (self.sum() >> 11) as f64 / ((1u64 << 53) - 1) as f64;
+        lo + (hi - lo) * u
+    }
+
+    /// Generates an `f32` tuple uniformly distributed in `[lo, hi)`, built on
+    /// `tuple_canonical_f32`, with each element clamped below `hi`.
+    pub fn range_f32(&mut self, lo: f32, hi: f32) -> (f32, f32) {
+        debug_assert!(lo < hi && lo.is_finite() && hi.is_finite());
+        let (a, b) = self.tuple_canonical_f32();
+        let map = |u: f32| {
+            let x = lo + (hi - lo) * u;
+            if x < hi { x } else { next_below_f32(hi) }
+        };
+        (map(a), map(b))
+    }
+
+    /// Generates a `u64` uniformly distributed in `[lo, hi)` using Lemire's
+    /// unbiased method: the draw is multiplied by the range width as a 128 bit
+    /// product and rejected only within the small low-bias zone.
+    pub fn range_u64(&mut self, lo: u64, hi: u64) -> u64 {
+        debug_assert!(lo < hi);
+        let range = hi - lo;
+        self.clock();
+        let mut product = (self.sum() as u128) * (range as u128);
+        let mut low = product as u64;
+        if low < range {
+            let threshold = range.wrapping_neg() % range;
+            while low < threshold {
+                self.clock();
+                product = (self.sum() as u128) * (range as u128);
+                low = product as u64;
+            }
+        }
+        lo + (product >> 64) as u64
+    }
+
+    /// Clocks the generator once and returns the resulting `u64`. This is the
+    /// raw integer output underpinning every float method, exposed for use
+    /// cases such as hashing salts, index selection and byte streams.
+    pub fn next_u64(&mut self) -> u64 {
+        self.clock();
+        self.sum()
+    }
+
+    /// Returns the high 32 bits of the next `u64`, which on the xoshiro family
+    /// are of higher quality than the low bits.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Fills `dst` with freshly generated `u64`s, clocking once per word in a
+    /// tight loop to amortize the per-call overhead.
+    pub fn fill_u64(&mut self, dst: &mut [u64]) {
+        for slot in dst.iter_mut() {
+            *slot = self.next_u64();
+        }
+    }
+
+    /// Fills `dst` with pseudorandom bytes, drawing one `u64` per 8 bytes and
+    /// copying a partial tail for any remainder.
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let bytes = self.next_u64().to_le_bytes();
+            tail.copy_from_slice(&bytes[..tail.len()]);
+        }
+    }
     
 }
+
+/// Abstracts the raw integer output of the crate's generators so a single
+/// [`StreamGen`] wrapper can decorate either of them.
+pub trait Clocked {
+    /// Construct the generator from a single 64 bit seed.
+    fn from_u64(seed: u64) -> Self;
+    /// Clock once and return the raw `u64` output.
+    fn raw_u64(&mut self) -> u64;
+}
+
+impl Clocked for Xorshift128p {
+    fn from_u64(seed: u64) -> Self {
+        Self::seed_from_u64(seed)
+    }
+    fn raw_u64(&mut self) -> u64 {
+        self.next_u64()
+    }
+}
+
+impl Clocked for Xoroshiro256pp {
+    fn from_u64(seed: u64) -> Self {
+        Self::seed_from_u64(seed)
+    }
+    fn raw_u64(&mut self) -> u64 {
+        self.next_u64()
+    }
+}
+
+/// Wraps a generator with a stream number so that one base seed yields a large
+/// family of decorrelated sequences, in the spirit of Krull64's pairwise
+/// independent streams. The stream number is expanded through SplitMix64; the
+/// result both perturbs the initial seed and is XORed into every output word.
+/// The decorrelation between streams comes from the perturbed seed — the
+/// constant per-stream XOR is just a fixed relabelling of the output — so
+/// distinct `stream` values give well-separated, though not provably
+/// independent, sequences, and a simulation can cheaply assign one stream per
+/// task id.
+/// # Examples
+/// ```rust
+/// use floaters::generators::{StreamGen, Xoroshiro256pp};
+/// let mut a = StreamGen::<Xoroshiro256pp>::with_stream(12345, 0);
+/// let mut b = StreamGen::<Xoroshiro256pp>::with_stream(12345, 1);
+/// assert!(a.next_u64() != b.next_u64());
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct StreamGen<G> {
+    gen: G,
+    mix: u64,
+}
+
+impl<G: Clocked> StreamGen<G> {
+    /// Create a stream-selected generator from a base `seed` and a `stream`
+    /// number.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut z = stream;
+        let mix = splitmix64(&mut z);
+        let gen = G::from_u64(seed ^ mix);
+        Self { gen, mix }
+    }
+
+    /// Clock the wrapped generator and return the stream-perturbed `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.gen.raw_u64() ^ self.mix
+    }
+}
+
+#[cfg(feature = "rand")]
+use rand_core::{RngCore, SeedableRng};
+
+#[cfg(feature = "rand")]
+impl RngCore for Xorshift128p {
+    fn next_u64(&mut self) -> u64 {
+        self.clock();
+        self.sum()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let bytes = self.next_u64().to_le_bytes();
+            tail.copy_from_slice(&bytes[..tail.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl SeedableRng for Xorshift128p {
+    type Seed = [u8; 16];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        // Expand each 8-byte group through its own SplitMix64 round so the
+        // whole seed reaches the state injectively, rather than folding the
+        // bytes into a single u64 and discarding most of the entropy.
+        let mut state = [0u64; 2];
+        for (word, chunk) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut z = u64::from_le_bytes(chunk.try_into().unwrap());
+            *word = splitmix64(&mut z);
+        }
+        Self { state }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl RngCore for Xoroshiro256pp {
+    fn next_u64(&mut self) -> u64 {
+        self.clock();
+        self.sum()
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            let bytes = self.next_u64().to_le_bytes();
+            tail.copy_from_slice(&bytes[..tail.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl SeedableRng for Xoroshiro256pp {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        // Expand each 8-byte group through its own SplitMix64 round so all
+        // 256 seed bits reach the state injectively, rather than folding the
+        // bytes into a single u64 and collapsing the seed to 64 bits.
+        let mut state = [0u64; 4];
+        for (word, chunk) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut z = u64::from_le_bytes(chunk.try_into().unwrap());
+            *word = splitmix64(&mut z);
+        }
+        Self { state }
+    }
+}
@@ -0,0 +1,60 @@
+//! Higher-level geometric sampling built on the crate's bit-level float
+//! generation. The functions here turn the signed uniform draws of the
+//! `NonCanonical` trait into uniformly distributed points on the unit circle
+//! and the unit sphere without a single trigonometric call.
+
+use rand::Rng;
+use crate::NonCanonical;
+
+/// Returns a point drawn uniformly from the unit circle as an `(x, y)` pair.
+/// Two signed uniforms are rejected until they fall inside the open unit disc,
+/// then mapped onto the circle via the rational parametrization, which avoids
+/// evaluating any trigonometric function.
+///
+/// # Example
+/// ```
+/// use rand_core::{RngCore, SeedableRng};
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+/// use floaters::geometry::on_unit_circle;
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(1234);
+/// let (x, y) = on_unit_circle(&mut rng);
+/// assert!((x * x + y * y - 1.0).abs() < 1e-9);
+/// ```
+pub fn on_unit_circle(rng: &mut impl Rng) -> (f64, f64) {
+    loop {
+        let x = rng.signed_uniform();
+        let y = rng.signed_uniform();
+        let s = x * x + y * y;
+        if s < 1.0 && s != 0.0 {
+            return ((x * x - y * y) / s, 2.0 * x * y / s);
+        }
+    }
+}
+
+/// Returns a point drawn uniformly from the unit sphere as an `(x, y, z)`
+/// triple using Marsaglia's method: two signed uniforms are rejected until
+/// they lie inside the unit disc, then lifted onto the sphere. This yields a
+/// uniform direction in three dimensions without any trigonometric call.
+///
+/// # Example
+/// ```
+/// use rand_core::{RngCore, SeedableRng};
+/// use rand_xoshiro::Xoshiro256PlusPlus;
+/// use floaters::geometry::on_unit_sphere;
+///
+/// let mut rng = Xoshiro256PlusPlus::seed_from_u64(1234);
+/// let (x, y, z) = on_unit_sphere(&mut rng);
+/// assert!((x * x + y * y + z * z - 1.0).abs() < 1e-9);
+/// ```
+pub fn on_unit_sphere(rng: &mut impl Rng) -> (f64, f64, f64) {
+    loop {
+        let x1 = rng.signed_uniform();
+        let x2 = rng.signed_uniform();
+        let s = x1 * x1 + x2 * x2;
+        if s < 1.0 {
+            let a = (1.0 - s).sqrt();
+            return (2.0 * x1 * a, 2.0 * x2 * a, 1.0 - 2.0 * s);
+        }
+    }
+}
@@ -5,7 +5,10 @@
 //! The values may be (roughly) evenly or unevenly distributed,
 //! depending on the chosen method.
 
+pub mod distributions;
 mod float;
+pub mod generators;
+pub mod geometry;
 pub mod utilities;
 
 pub use crate::float::*;